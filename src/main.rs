@@ -1,59 +1,301 @@
 use bstr::ByteSlice;
 use esp32_nimble::{
-    utilities::BleUuid, uuid128, BLEAdvertisementData, BLEDevice, NimbleProperties,
+    enums::{AuthReq, SecurityIOCap},
+    utilities::{BleUuid, Mutex},
+    uuid128, BLEAdvertisementData, BLEAdvertising, BLECharacteristic, BLEDevice, NimbleProperties,
 };
 use esp_idf_svc::hal::{
-    delay, gpio, ledc, peripheral::Peripheral, peripherals::Peripherals, units::Hertz,
+    adc::{attenuation, oneshot::AdcChannelDriver, oneshot::AdcDriver},
+    delay,
+    gpio::{self, PinDriver, Pull},
+    ledc,
+    peripheral::Peripheral,
+    peripherals::Peripherals,
+    units::Hertz,
 };
+use esp_idf_svc::nvs::{EspDefaultNvsPartition, EspNvs, NvsDefault};
 use log::{info, warn};
-use std::sync::mpsc::{sync_channel, SyncSender};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::{sync_channel, SyncSender},
+    Arc,
+};
+
+/// How strongly an incoming BLE connection must prove its identity before
+/// `BLE_CMD_UUID` writes are accepted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SecurityLevel {
+    /// No pairing required; any peer can connect and write (legacy behavior).
+    None,
+    /// Pairing is required but without MITM protection (no passkey exchange).
+    JustWorks,
+    /// Pairing requires the peer to enter the given 6-digit passkey.
+    Passkey(u32),
+}
+
+/// Returns this unit's pairing passkey, generating and persisting one to NVS
+/// on first boot. The passkey comes from the hardware RNG rather than
+/// anything broadcast over the air (e.g. the BLE MAC), so it can't be
+/// computed by an eavesdropper watching advertisements.
+fn device_passkey() -> anyhow::Result<u32> {
+    const NAMESPACE: &str = "ble_lock";
+    const KEY: &str = "passkey";
+
+    let mut nvs: EspNvs<NvsDefault> =
+        EspNvs::new(EspDefaultNvsPartition::take()?, NAMESPACE, true)?;
+    if let Some(passkey) = nvs.get_u32(KEY)? {
+        return Ok(passkey);
+    }
 
-fn init_ble(sender: SyncSender<Vec<u8>>) -> Result<(), esp32_nimble::BLEError> {
+    let passkey = 100_000 + unsafe { esp_idf_svc::sys::esp_random() } % 900_000;
+    nvs.set_u32(KEY, passkey)?;
+    // Logged over the serial console (not BLE) so the owner can read it out
+    // during provisioning.
+    info!("Generated BLE pairing passkey for first-time provisioning: {passkey}");
+    Ok(passkey)
+}
+
+/// Handles (characteristics, the advertiser and the discoverable flag) that
+/// `main` and its worker threads need after `init_ble` has handed the server
+/// off to NimBLE.
+struct BleHandles {
+    cmd: Arc<Mutex<BLECharacteristic>>,
+    battery_level: Arc<Mutex<BLECharacteristic>>,
+    advertising: Arc<Mutex<BLEAdvertising>>,
+    discoverable: Arc<AtomicBool>,
+}
+
+fn init_ble(
+    sender: SyncSender<Vec<u8>>,
+    security: SecurityLevel,
+) -> Result<BleHandles, esp32_nimble::BLEError> {
     // BLE GATT UUIDs
     static BLE_SERVICE_UUID: BleUuid = uuid128!("87cde903-dd98-4bda-b3ac-ee6e1718f373");
     static BLE_CMD_UUID: BleUuid = uuid128!("047c2b6b-97b5-4b0c-adba-bbea3f7fb2e2");
+    // Standard GATT Battery Service / Battery Level characteristic.
+    static BATTERY_SERVICE_UUID: BleUuid = BleUuid::from_uuid16(0x180F);
+    static BATTERY_LEVEL_UUID: BleUuid = BleUuid::from_uuid16(0x2A19);
 
     let ble_device = BLEDevice::take();
+
+    // Security: require encryption/authentication unless explicitly disabled.
+    // Bond persistence is configured in sdkconfig.defaults
+    // (CONFIG_BT_NIMBLE_NVS_PERSIST).
+    match security {
+        SecurityLevel::None => {}
+        SecurityLevel::JustWorks => {
+            // No Mitm bit: NoInputNoOutput can't confirm a passkey/numeric
+            // comparison, so asking for MITM protection here would be a lie.
+            ble_device
+                .security()
+                .set_auth(AuthReq::Bond | AuthReq::Sc)
+                .set_io_cap(SecurityIOCap::NoInputNoOutput);
+        }
+        SecurityLevel::Passkey(passkey) => {
+            ble_device
+                .security()
+                .set_auth(AuthReq::all())
+                .set_passkey(passkey)
+                .set_io_cap(SecurityIOCap::DisplayOnly);
+        }
+    }
+
     let ble_server = ble_device.get_server(); // not need start manually
     let ble_advertising = ble_device.get_advertising();
+    let discoverable = Arc::new(AtomicBool::new(true));
 
     // BLE Server Configuration
-    ble_server.advertise_on_disconnect(true);
+    // Advertising is restarted from `on_disconnect` instead, gated on
+    // `discoverable`, so a long-press "off" toggle stays authoritative
+    // across disconnects.
+    ble_server.advertise_on_disconnect(false);
     ble_server.on_connect(|server, desc| {
         info!("Connected to device: {}", desc.address());
         let _ = server.update_conn_params(desc.conn_handle(), 24, 48, 0, 60);
     });
-    ble_server.on_disconnect(|desc, _| info!("Disconnected from device: {}", desc.address()));
+    {
+        let ble_advertising = ble_advertising.clone();
+        let discoverable = discoverable.clone();
+        ble_server.on_disconnect(move |desc, _| {
+            info!("Disconnected from device: {}", desc.address());
+            if discoverable.load(Ordering::SeqCst) {
+                if let Err(err) = ble_advertising.lock().start() {
+                    warn!("Failed to resume advertising after disconnect: {:?}", err);
+                }
+            }
+        });
+    }
 
     // BLE Service Configuration
     let ble_service = ble_server.create_service(BLE_SERVICE_UUID);
-    let writable_characteristic_cmd = ble_service.lock().create_characteristic(
-        BLE_CMD_UUID,
-        NimbleProperties::READ | NimbleProperties::WRITE | NimbleProperties::NOTIFY,
-    );
+    let cmd_properties = match security {
+        SecurityLevel::None => {
+            NimbleProperties::READ | NimbleProperties::WRITE | NimbleProperties::NOTIFY
+        }
+        // WRITE_ENC makes the stack reject writes from a peer that hasn't
+        // completed encrypted pairing/bonding. JustWorks pairing can't clear
+        // WRITE_AUTHEN's MITM requirement, so asking for it here would make
+        // every write fail even after a successful bond.
+        SecurityLevel::JustWorks => {
+            NimbleProperties::READ | NimbleProperties::WRITE_ENC | NimbleProperties::NOTIFY
+        }
+        // WRITE_AUTHEN additionally requires the MITM-protected link that
+        // passkey entry provides.
+        SecurityLevel::Passkey(_) => {
+            NimbleProperties::READ
+                | NimbleProperties::WRITE_ENC
+                | NimbleProperties::WRITE_AUTHEN
+                | NimbleProperties::NOTIFY
+        }
+    };
+    let writable_characteristic_cmd = ble_service
+        .lock()
+        .create_characteristic(BLE_CMD_UUID, cmd_properties);
+    let busy_characteristic = writable_characteristic_cmd.clone();
     writable_characteristic_cmd.lock().on_write(move |cmd| {
         let data = cmd.recv_data();
         info!("Received command: {}", data.as_bstr());
         if let Err(_) = sender.try_send(data.to_vec()) {
             warn!("is busy");
+            notify_status(&busy_characteristic, "BUSY");
         }
     });
 
+    // Battery Service Configuration
+    let battery_service = ble_server.create_service(BATTERY_SERVICE_UUID);
+    let battery_level_characteristic = battery_service.lock().create_characteristic(
+        BATTERY_LEVEL_UUID,
+        NimbleProperties::READ | NimbleProperties::NOTIFY,
+    );
+    battery_level_characteristic.lock().set_value(&[100]);
+
     // BLE Start Advertising
     ble_advertising.lock().set_data(
         BLEAdvertisementData::new()
             .name("BLE Lock")
-            .add_service_uuid(BLE_SERVICE_UUID),
+            .add_service_uuid(BLE_SERVICE_UUID)
+            .add_service_uuid(BATTERY_SERVICE_UUID),
     )?;
     ble_advertising.lock().min_interval(1280); // 800ms
     ble_advertising.lock().max_interval(1600); // 1000ms
     ble_advertising.lock().scan_response(false);
-    ble_advertising.lock().start()
+    ble_advertising.lock().start()?;
+
+    Ok(BleHandles {
+        cmd: writable_characteristic_cmd,
+        battery_level: battery_level_characteristic,
+        advertising: ble_advertising,
+        discoverable,
+    })
+}
+
+/// Publishes a command result onto `BLE_CMD_UUID`.
+fn notify_status(characteristic: &Arc<Mutex<BLECharacteristic>>, status: &str) {
+    let mut characteristic = characteristic.lock();
+    characteristic.set_value(status.as_bytes());
+    characteristic.notify();
+}
+
+/// Supply rail voltage, in millivolts, corresponding to 0% and 100% charge.
+/// Tuned for a single-cell Li-ion/LiPo pack; adjust if the lock is powered
+/// differently.
+const BATTERY_EMPTY_MV: u32 = 3300;
+const BATTERY_FULL_MV: u32 = 4200;
+
+/// `BATTERY_FULL_MV` exceeds the ADC's usable input range (and its absolute
+/// max rating), so VBAT must go through an external resistor divider before
+/// reaching the ADC pin. This is that divider's ratio (Vbat / Vadc); a 1:1
+/// divider (two equal resistors) gives 2.0, halving 4.2V down to 2.1V.
+const BATTERY_DIVIDER_RATIO: u32 = 2;
+
+/// Converts a divided-down ADC reading into a 0-100 battery percentage.
+fn battery_percent_from_mv(adc_mv: u32) -> u8 {
+    let mv = (adc_mv * BATTERY_DIVIDER_RATIO).clamp(BATTERY_EMPTY_MV, BATTERY_FULL_MV);
+    let percent = (mv - BATTERY_EMPTY_MV) * 100 / (BATTERY_FULL_MV - BATTERY_EMPTY_MV);
+    percent as u8
 }
 
+/// Samples the supply rail and notifies `battery_level` on change.
+fn run_battery_monitor(
+    mut adc: AdcDriver<'static, gpio::ADC1>,
+    mut channel: AdcChannelDriver<'static, { attenuation::DB_11 }, gpio::Gpio0>,
+    battery_level: Arc<Mutex<BLECharacteristic>>,
+) {
+    let mut last_percent = None;
+    loop {
+        match adc.read(&mut channel) {
+            Ok(mv) => {
+                let percent = battery_percent_from_mv(mv as u32);
+                if last_percent != Some(percent) {
+                    info!("Battery level: {}%", percent);
+                    let mut characteristic = battery_level.lock();
+                    characteristic.set_value(&[percent]);
+                    characteristic.notify();
+                    last_percent = Some(percent);
+                }
+            }
+            Err(err) => warn!("Battery ADC read failed: {:?}", err),
+        }
+        delay::FreeRtos::delay_ms(30_000);
+    }
+}
+
+/// How long the boot button must be held to toggle advertising.
+const LONG_PRESS_MS: u32 = 3000;
+/// How often the boot button is polled while watching for a long press.
+const BUTTON_POLL_MS: u32 = 50;
+
+/// Toggles advertising on/off on a long press. Button is active-low.
+fn run_boot_button_monitor(
+    mut button: PinDriver<'static, gpio::Gpio3, gpio::Input>,
+    advertising: Arc<Mutex<BLEAdvertising>>,
+    discoverable: Arc<AtomicBool>,
+) {
+    let mut held_ms = 0u32;
+    let mut toggled_this_press = false;
+    loop {
+        if button.is_low() {
+            held_ms += BUTTON_POLL_MS;
+            if held_ms >= LONG_PRESS_MS && !toggled_this_press {
+                toggled_this_press = true;
+                let now_discoverable = !discoverable.fetch_xor(true, Ordering::SeqCst);
+                let mut advertising = advertising.lock();
+                let result = if now_discoverable {
+                    advertising.start()
+                } else {
+                    advertising.stop()
+                };
+                match result {
+                    Ok(()) => info!(
+                        "Advertising {}",
+                        if now_discoverable {
+                            "started"
+                        } else {
+                            "stopped"
+                        }
+                    ),
+                    Err(err) => warn!("Failed to toggle advertising: {:?}", err),
+                }
+            }
+        } else {
+            held_ms = 0;
+            toggled_this_press = false;
+        }
+        delay::FreeRtos::delay_ms(BUTTON_POLL_MS);
+    }
+}
+
+/// How long a `move_to` ramp takes when the caller (e.g. `hold_for`) doesn't
+/// otherwise specify one.
+const DEFAULT_MOVE_MS: u32 = 300;
+/// Target frame time for `move_to`'s ramp, matched to the 50Hz PWM period.
+const MOVE_FRAME_MS: u32 = 20;
+
 struct SG90<'a> {
     ledc: ledc::LedcDriver<'a>,
     max_duty: u32,
+    /// Last commanded degree, used as the ramp start point for the next move.
+    degree: u8,
 }
 
 impl<'a> SG90<'a> {
@@ -77,15 +319,158 @@ impl<'a> SG90<'a> {
             pin,
         )?;
         let max_duty = ledc.get_max_duty();
-        Ok(Self { ledc, max_duty })
+        Ok(Self {
+            ledc,
+            max_duty,
+            degree: 0,
+        })
     }
 
     fn set_degree(&mut self, degree: u8) -> Result<(), esp_idf_svc::sys::EspError> {
         let duty = (degree as f32 / 1800.0) + 0.025;
         let duty = duty * self.max_duty as f32;
         self.ledc.set_duty(duty as u32)?;
+        self.degree = degree;
         Ok(())
     }
+
+    /// Ramps smoothly from the current degree to `target` over `duration_ms`,
+    /// instead of snapping instantly (which slams the SG90 and spikes current).
+    /// Uses a cubic ease-in-out so the motion accelerates then decelerates.
+    fn move_to(&mut self, target: u8, duration_ms: u32) -> Result<(), esp_idf_svc::sys::EspError> {
+        let target = target.min(180);
+        let start = self.degree;
+        if start == target || duration_ms == 0 {
+            return self.set_degree(target);
+        }
+
+        let frames = (duration_ms / MOVE_FRAME_MS).max(1);
+        let delta = target as f32 - start as f32;
+        for i in 1..=frames {
+            let t = i as f32 / frames as f32;
+            let eased = if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+            };
+            let position = start as f32 + delta * eased;
+            let duty = (position / 1800.0 + 0.025) * self.max_duty as f32;
+            self.ledc.set_duty(duty as u32)?;
+            delay::FreeRtos::delay_ms(MOVE_FRAME_MS);
+        }
+        self.degree = target;
+        Ok(())
+    }
+
+    /// Moves to `degree` and holds there for `ms` before returning.
+    fn hold_for(&mut self, degree: u8, ms: u32) -> Result<(), esp_idf_svc::sys::EspError> {
+        self.move_to(degree, DEFAULT_MOVE_MS)?;
+        delay::FreeRtos::delay_ms(ms);
+        Ok(())
+    }
+
+    /// Steps from `from` to `to` in increments of `step`, ramping smoothly
+    /// over `ms` between each step. `step` is always treated as a positive
+    /// magnitude; direction is inferred from `from`/`to`.
+    fn sweep(
+        &mut self,
+        from: u8,
+        to: u8,
+        step: u8,
+        ms: u32,
+    ) -> Result<(), esp_idf_svc::sys::EspError> {
+        let step = step.max(1);
+        self.move_to(from, DEFAULT_MOVE_MS)?;
+        if from <= to {
+            let mut degree = from;
+            while degree < to {
+                degree = degree.saturating_add(step).min(to);
+                self.move_to(degree, ms)?;
+            }
+        } else {
+            let mut degree = from;
+            while degree > to {
+                degree = degree.saturating_sub(step).max(to);
+                self.move_to(degree, ms)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Servos addressed by index.
+struct ServoBank<'a> {
+    servos: Vec<SG90<'a>>,
+}
+
+impl<'a> ServoBank<'a> {
+    fn new(servos: Vec<SG90<'a>>) -> Self {
+        Self { servos }
+    }
+
+    fn get_mut(&mut self, id: usize) -> Option<&mut SG90<'a>> {
+        self.servos.get_mut(id)
+    }
+
+    fn len(&self) -> usize {
+        self.servos.len()
+    }
+}
+
+/// A parsed `BLE_CMD_UUID` write. See [`Command::parse`] for the grammar.
+enum Command {
+    /// `SET <id> <deg>` — move servo `id` to `deg`, then return to 0 if auto-return is on
+    /// (the default, matching the legacy behavior).
+    Set { id: usize, degree: u8 },
+    /// `HOLD <id> <deg> <ms>` — move servo `id` to `deg` and hold for `ms` milliseconds.
+    Hold { id: usize, degree: u8, ms: u32 },
+    /// `SWEEP <id> <from> <to> <step> <ms>` — step servo `id` from `from` to `to`.
+    Sweep {
+        id: usize,
+        from: u8,
+        to: u8,
+        step: u8,
+        ms: u32,
+    },
+    /// `RETURN on|off` — toggle whether `SET`/`HOLD`/`SWEEP` auto-return to 0 afterwards.
+    Return(bool),
+}
+
+impl Command {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let text = data.to_str_lossy();
+        let mut tokens = text.split_whitespace();
+        match tokens.next()?.to_ascii_uppercase().as_str() {
+            "SET" => Some(Command::Set {
+                id: tokens.next()?.parse().ok()?,
+                degree: tokens.next()?.parse().ok()?,
+            }),
+            "HOLD" => Some(Command::Hold {
+                id: tokens.next()?.parse().ok()?,
+                degree: tokens.next()?.parse().ok()?,
+                ms: tokens.next()?.parse().ok()?,
+            }),
+            "SWEEP" => Some(Command::Sweep {
+                id: tokens.next()?.parse().ok()?,
+                from: tokens.next()?.parse().ok()?,
+                to: tokens.next()?.parse().ok()?,
+                step: tokens.next()?.parse().ok()?,
+                ms: tokens.next()?.parse().ok()?,
+            }),
+            "RETURN" => match tokens.next()? {
+                "on" => Some(Command::Return(true)),
+                "off" => Some(Command::Return(false)),
+                _ => None,
+            },
+            // Bare degree, e.g. "90" — kept for backwards compatibility with
+            // older controller apps that predate the structured grammar;
+            // always addresses servo 0.
+            other => other
+                .parse()
+                .ok()
+                .map(|degree| Command::Set { id: 0, degree }),
+        }
+    }
 }
 
 fn main() -> anyhow::Result<()> {
@@ -97,34 +482,125 @@ fn main() -> anyhow::Result<()> {
     esp_idf_svc::log::EspLogger::initialize_default();
 
     let (sender, receiver) = sync_channel(1);
-    init_ble(sender)?;
+    let ble = init_ble(sender, SecurityLevel::Passkey(device_passkey()?))?;
+    let cmd_characteristic = ble.cmd;
 
     let peripherals = Peripherals::take()?;
-    let mut st90 = SG90::new(
-        peripherals.ledc.channel0,
-        peripherals.ledc.timer0,
-        peripherals.pins.gpio9,
-    )?;
+    let mut servos = ServoBank::new(vec![
+        SG90::new(
+            peripherals.ledc.channel0,
+            peripherals.ledc.timer0,
+            peripherals.pins.gpio9,
+        )?,
+        SG90::new(
+            peripherals.ledc.channel1,
+            peripherals.ledc.timer1,
+            peripherals.pins.gpio10,
+        )?,
+    ]);
+
+    let battery_adc = AdcDriver::new(peripherals.adc1)?;
+    // gpio0 reads VBAT through the resistor divider described by
+    // BATTERY_DIVIDER_RATIO, not VBAT directly — the pack voltage would
+    // clip the ADC and exceed its absolute max input rating otherwise.
+    let battery_channel: AdcChannelDriver<{ attenuation::DB_11 }, _> =
+        AdcChannelDriver::new(peripherals.pins.gpio0)?;
+    std::thread::Builder::new()
+        .stack_size(4096)
+        .spawn(move || run_battery_monitor(battery_adc, battery_channel, ble.battery_level))?;
+
+    // Boot button: adjust the pin to match wherever it's wired on your board.
+    let mut boot_button = PinDriver::input(peripherals.pins.gpio3)?;
+    boot_button.set_pull(Pull::Up)?;
+    std::thread::Builder::new()
+        .stack_size(4096)
+        .spawn(move || run_boot_button_monitor(boot_button, ble.advertising, ble.discoverable))?;
 
-    st90.set_degree(0)?;
+    for id in 0..servos.len() {
+        servos.get_mut(id).unwrap().set_degree(0)?;
+    }
     delay::FreeRtos::delay_ms(1000);
 
+    // Whether SET/HOLD/SWEEP snap back to 0 after completing, toggled by `RETURN on|off`.
+    let mut auto_return = true;
+
     loop {
         let data = receiver.recv()?;
-        if let Ok(degree) = data.to_str_lossy().parse::<u8>() {
-            if degree > 180 {
-                warn!("Invalid degree: {}", degree);
-                continue;
-            }
-            info!("Set degree: {}", degree);
-
-            st90.set_degree(degree)?;
-            delay::FreeRtos::delay_ms(1000);
-
-            st90.set_degree(0)?;
-            delay::FreeRtos::delay_ms(1000);
-        } else {
+        let Some(command) = Command::parse(&data) else {
             warn!("Invalid command: {}", data.as_bstr());
+            notify_status(&cmd_characteristic, "ERR invalid");
+            continue;
+        };
+
+        match command {
+            Command::Set { id, degree } => {
+                let Some(servo) = servos.get_mut(id) else {
+                    warn!("Invalid servo id: {}", id);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                };
+                if degree > 180 {
+                    warn!("Invalid degree: {}", degree);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                }
+                info!("Set servo {} degree: {}", id, degree);
+                servo.hold_for(degree, 1000)?;
+                if auto_return {
+                    servo.hold_for(0, 1000)?;
+                }
+                notify_status(&cmd_characteristic, &format!("OK {id} {degree}"));
+            }
+            Command::Hold { id, degree, ms } => {
+                let Some(servo) = servos.get_mut(id) else {
+                    warn!("Invalid servo id: {}", id);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                };
+                if degree > 180 {
+                    warn!("Invalid degree: {}", degree);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                }
+                info!("Hold servo {} degree: {} for {}ms", id, degree, ms);
+                servo.hold_for(degree, ms)?;
+                if auto_return {
+                    servo.hold_for(0, 1000)?;
+                }
+                notify_status(&cmd_characteristic, &format!("OK {id} {degree}"));
+            }
+            Command::Sweep {
+                id,
+                from,
+                to,
+                step,
+                ms,
+            } => {
+                let Some(servo) = servos.get_mut(id) else {
+                    warn!("Invalid servo id: {}", id);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                };
+                if from > 180 || to > 180 {
+                    warn!("Invalid sweep range: {}..{}", from, to);
+                    notify_status(&cmd_characteristic, "ERR invalid");
+                    continue;
+                }
+                info!(
+                    "Sweep servo {} {}..{} step {} every {}ms",
+                    id, from, to, step, ms
+                );
+                servo.sweep(from, to, step, ms)?;
+                if auto_return {
+                    servo.hold_for(0, 1000)?;
+                }
+                notify_status(&cmd_characteristic, &format!("OK {id} {to}"));
+            }
+            Command::Return(enabled) => {
+                info!("Auto-return: {}", enabled);
+                auto_return = enabled;
+                notify_status(&cmd_characteristic, "OK");
+            }
         }
     }
 }